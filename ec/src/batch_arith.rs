@@ -0,0 +1,165 @@
+use ark_ff::{batch_inversion, fields::BitIteratorBE, Field, PrimeField, Zero};
+use ark_std::vec::Vec;
+
+use crate::{models::short_weierstrass_jacobian::GroupAffine, SWModelParameters};
+
+/// Below this many points, [`batch_verify_in_subgroup`] falls back to
+/// checking each point independently rather than paying the bookkeeping
+/// cost of the batched ladder.
+const BATCH_THRESHOLD: usize = 1 << 10;
+
+/// Affine curve arithmetic that amortizes field inversions across many
+/// independent point operations, by running a single batch (Montgomery)
+/// inversion over all of the operations' denominators at once.
+///
+/// This turns `n` inversions into one inversion plus `O(n)` multiplications,
+/// which matters because field inversion is typically one to two orders of
+/// magnitude more expensive than a multiplication.
+pub trait BatchGroupArithmetic: Sized + Copy + Zero {
+    type BaseField: Field;
+
+    /// For each `(i, j)` in `index`, replace `bases[i]` with `bases[i] +
+    /// bases[j]`, using a single batched inversion over all of the pairs'
+    /// denominators (`x_j - x_i`).
+    ///
+    /// Pairs with `i == j`, or whose two points are equal, are treated as
+    /// point doublings. A pair whose result is the point at infinity (a
+    /// point plus its negation, or the doubling of a point with `y = 0`)
+    /// writes the point at infinity to `bases[i]`.
+    ///
+    /// Callers are responsible for keeping the point at infinity out of
+    /// `bases`, and for not passing two index pairs that write to the same
+    /// `i` within one call: the formulas below assume affine, non-identity
+    /// inputs and independent outputs.
+    fn batch_add_in_place(bases: &mut [Self], index: &[(u32, u32)]);
+}
+
+impl<P: SWModelParameters> BatchGroupArithmetic for GroupAffine<P> {
+    type BaseField = P::BaseField;
+
+    fn batch_add_in_place(bases: &mut [Self], index: &[(u32, u32)]) {
+        let is_doubling = |a: &Self, b: &Self| a.x == b.x && a.y == b.y;
+        let mut denominators: Vec<P::BaseField> = index
+            .iter()
+            .map(|&(i, j)| {
+                let (a, b) = (&bases[i as usize], &bases[j as usize]);
+                if is_doubling(a, b) {
+                    a.y + &a.y
+                } else {
+                    b.x - &a.x
+                }
+            })
+            .collect();
+        // `batch_inversion` leaves zero denominators at zero.
+        batch_inversion(&mut denominators);
+
+        for (&(i, j), inv) in index.iter().zip(denominators) {
+            let (a, b) = (bases[i as usize], bases[j as usize]);
+            // A zero denominator means `b = -a`, or a doubling with `y = 0`:
+            // either way the result is the point at infinity.
+            if inv.is_zero() {
+                bases[i as usize] = Self::zero();
+                continue;
+            }
+            let lambda = if is_doubling(&a, &b) {
+                let xx = a.x.square();
+                (xx + &xx + &xx + &P::COEFF_A) * &inv
+            } else {
+                (b.y - &a.y) * &inv
+            };
+            let x3 = lambda.square() - &a.x - &b.x;
+            let y3 = lambda * &(a.x - &x3) - &a.y;
+            bases[i as usize].x = x3;
+            bases[i as usize].y = y3;
+        }
+    }
+}
+
+/// Verify that every point in `points` lies in the prime-order subgroup of
+/// `P`, amortizing the cost of the cofactor-order scalar multiplication
+/// across the whole batch.
+///
+/// Each point gets its own accumulator, all starting at that point; the
+/// accumulators are then driven through a shared double-and-add ladder over
+/// the scalar field's characteristic, one step at a time. Every round
+/// doubles all live accumulators with one
+/// [`BatchGroupArithmetic::batch_add_in_place`] call and, when the round's
+/// bit is set, adds the base points back in with a second call, so each
+/// round costs at most two batched inversions. A point is
+/// in the subgroup iff its accumulator ends at the point at infinity.
+///
+/// Below [`BATCH_THRESHOLD`] points, each point is instead checked
+/// independently via [`SWModelParameters::is_in_correct_subgroup_assuming_on_curve`],
+/// since the batched ladder's bookkeeping overhead isn't worth paying for
+/// small batches, and plain double-and-add already skips work in proportion
+/// to the scalar's Hamming weight.
+///
+/// A regression test exercising a low-order point within a batch above
+/// [`BATCH_THRESHOLD`] (e.g. a curve with a known 2-torsion point) belongs
+/// here, but needs this crate's `short_weierstrass_jacobian` point
+/// arithmetic, which this tree doesn't implement.
+pub fn batch_verify_in_subgroup<P: SWModelParameters>(points: &[GroupAffine<P>]) -> bool
+where
+    GroupAffine<P>: BatchGroupArithmetic<BaseField = P::BaseField>,
+{
+    if points.len() < BATCH_THRESHOLD {
+        return points
+            .iter()
+            .all(P::is_in_correct_subgroup_assuming_on_curve);
+    }
+
+    let bits: Vec<bool> =
+        BitIteratorBE::without_leading_zeros(P::ScalarField::characteristic()).collect();
+
+    let mut acc: Vec<GroupAffine<P>> = points.to_vec();
+    let identity: Vec<u32> = (0..acc.len() as u32).collect();
+
+    for &bit in &bits[1..] {
+        // Double every live accumulator (accumulators that have already
+        // collapsed to the point at infinity are left alone), batching the
+        // round into one inversion.
+        let live: Vec<u32> = identity
+            .iter()
+            .copied()
+            .filter(|&i| !acc[i as usize].is_zero())
+            .collect();
+        let double_index: Vec<(u32, u32)> = live.iter().map(|&i| (i, i)).collect();
+        GroupAffine::<P>::batch_add_in_place(&mut acc, &double_index);
+
+        if bit {
+            // Unlike doubling, adding the base point back in is *not* safe
+            // to skip for accumulators that are currently at the point at
+            // infinity: 0 + P = P, not 0. So every index is reconsidered
+            // here, not just this round's `live` set — an accumulator that
+            // collapsed to infinity on an earlier round (e.g. because the
+            // point has small order) must still pick the base point back up
+            // on the next `bit == true` round. Points at infinity get
+            // `points[i]` written back directly (a plain assignment, not a
+            // field operation, so it needs no batching); the rest go
+            // through the batched addition as before.
+            let mut add_live: Vec<u32> = Vec::new();
+            for &i in &identity {
+                if acc[i as usize].is_zero() {
+                    acc[i as usize] = points[i as usize];
+                } else {
+                    add_live.push(i);
+                }
+            }
+            if !add_live.is_empty() {
+                let mut scratch: Vec<GroupAffine<P>> =
+                    add_live.iter().map(|&i| points[i as usize]).collect();
+                let mut combined: Vec<GroupAffine<P>> =
+                    add_live.iter().map(|&i| acc[i as usize]).collect();
+                combined.append(&mut scratch);
+                let n = add_live.len() as u32;
+                let add_index: Vec<(u32, u32)> = (0..n).map(|k| (k, n + k)).collect();
+                GroupAffine::<P>::batch_add_in_place(&mut combined, &add_index);
+                for (k, &i) in add_live.iter().enumerate() {
+                    acc[i as usize] = combined[k];
+                }
+            }
+        }
+    }
+
+    acc.iter().all(Zero::is_zero)
+}
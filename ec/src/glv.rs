@@ -0,0 +1,188 @@
+use ark_ff::{fields::BitIteratorBE, BigInteger, Field, PrimeField, Zero};
+use ark_std::vec::Vec;
+
+use crate::{
+    models::short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    SWModelParameters,
+};
+
+/// Multiply the (little-endian, full-width) limbs of a big integer by a
+/// 128-bit constant, returning the wide product's limbs, least significant
+/// first. `result[2..]` is therefore the product shifted right by 128 bits.
+fn wide_mul_u128(limbs: &[u64], rhs: u128) -> Vec<u64> {
+    let rhs_limbs = [rhs as u64, (rhs >> 64) as u64];
+    let mut result = ark_std::vec![0u64; limbs.len() + 2];
+
+    for (i, &limb) in limbs.iter().enumerate() {
+        let mut carry: u128 = 0;
+        for (j, &rhs_limb) in rhs_limbs.iter().enumerate() {
+            let idx = i + j;
+            let prod = (limb as u128) * (rhs_limb as u128) + (result[idx] as u128) + carry;
+            result[idx] = prod as u64;
+            carry = prod >> 64;
+        }
+        let mut idx = i + rhs_limbs.len();
+        while carry != 0 {
+            let sum = result[idx] as u128 + carry;
+            result[idx] = sum as u64;
+            carry = sum >> 64;
+            idx += 1;
+        }
+    }
+    result
+}
+
+/// Companion trait for [`SWModelParameters`] implementors that admit an
+/// efficiently computable endomorphism `φ` with `φ(P) = [λ]P` for some curve
+/// eigenvalue `λ` (e.g. BLS12-377 G1, where `φ(x, y) = (β·x, y)` for a
+/// primitive cube root of unity `β`).
+///
+/// A [`mul_glv`](Self::mul_glv) computed this way costs roughly half of a
+/// plain scalar multiplication, since it replaces one full-length
+/// double-and-add with two half-length ones sharing the same doublings.
+pub trait GLVParameters: SWModelParameters + Sized {
+    /// The endomorphism's eigenvalue: `φ(P) = [LAMBDA]P`.
+    const LAMBDA: Self::ScalarField;
+    /// The base-field constant the endomorphism multiplies the
+    /// `x`-coordinate by.
+    const OMEGA: Self::BaseField;
+
+    /// `⌊g_i · 2^128 / r⌋` for `i ∈ {1, 2}`, the rounding constants used to
+    /// compute `b_i = round(k · g_i / r)` without a full modulus-width
+    /// division.
+    const G1: u128;
+    const G2: u128;
+
+    /// The short lattice basis vectors `v1 = (v1x, v1y)`, `v2 = (v2x, v2y)`
+    /// used to turn the rounding coefficients `b1, b2` into the
+    /// decomposition `k1, k2`.
+    const V1: (Self::ScalarField, Self::ScalarField);
+    const V2: (Self::ScalarField, Self::ScalarField);
+
+    /// `⌊r / 2⌋`, used to recover the sign of a decomposed half-scalar from
+    /// its canonical (always non-negative) field representative.
+    const HALF_R: <Self::ScalarField as PrimeField>::BigInt;
+
+    /// Apply the endomorphism `φ` to an affine point.
+    fn endomorphism(base: &GroupAffine<Self>) -> GroupAffine<Self>;
+
+    /// Decompose `k` into `(k1, k2)` with `k ≡ k1 + k2·LAMBDA (mod r)`, each
+    /// roughly half the bit length of `r`, via balanced-representation
+    /// rounding: `b1 = round(k·g1/r)`, `b2 = round(k·g2/r)`, `k1 = k -
+    /// b1·v1x - b2·v2x`, `k2 = -b1·v1y - b2·v2y`.
+    fn scalar_decomposition(k: Self::ScalarField) -> (Self::ScalarField, Self::ScalarField) {
+        // round(k * g_i / r) ~= (k * floor(g_i * 2^128 / r)) >> 128, computed
+        // as a full-width multiply of every limb of `k` (not just its low
+        // 128 bits) by the 128-bit constant `g_i`, keeping the whole wide
+        // product before shifting right by 2 words (128 bits).
+        let k_limbs = k.into_repr();
+        let b1 = Self::field_from_limbs(&wide_mul_u128(k_limbs.as_ref(), Self::G1)[2..]);
+        let b2 = Self::field_from_limbs(&wide_mul_u128(k_limbs.as_ref(), Self::G2)[2..]);
+
+        let k1 = k - &(b1 * &Self::V1.0) - &(b2 * &Self::V2.0);
+        let k2 = -(b1 * &Self::V1.1) - &(b2 * &Self::V2.1);
+        (k1, k2)
+    }
+
+    /// Fold big-endian-significant 64-bit limbs (least significant first,
+    /// matching [`ark_ff::BigInteger::as_ref`]) into a scalar field element.
+    fn field_from_limbs(limbs: &[u64]) -> Self::ScalarField {
+        let mut acc = Self::ScalarField::zero();
+        for &limb in limbs.iter().rev() {
+            for _ in 0..64 {
+                acc.double_in_place();
+            }
+            acc += &Self::ScalarField::from(limb);
+        }
+        acc
+    }
+
+    /// Whether `x`'s canonical representative denotes a "negative" balanced
+    /// residue, i.e. is greater than `⌊r/2⌋`.
+    fn is_negative(x: &Self::ScalarField) -> bool {
+        x.into_repr() > Self::HALF_R
+    }
+
+    /// Compute `[k]P` as `[k1]P + [k2]φ(P)`, with the signs of `k1, k2`
+    /// folded into `P` and `φ(P)` and the two half-length scalars
+    /// multiplied with an interleaved double-and-add sharing one sequence
+    /// of doublings.
+    fn mul_glv(base: &GroupAffine<Self>, k: Self::ScalarField) -> GroupProjective<Self> {
+        let (k1, k2) = Self::scalar_decomposition(k);
+
+        let (p1, k1) = if Self::is_negative(&k1) {
+            (-*base, -k1)
+        } else {
+            (*base, k1)
+        };
+        let phi_p = Self::endomorphism(base);
+        let (p2, k2) = if Self::is_negative(&k2) {
+            (-phi_p, -k2)
+        } else {
+            (phi_p, k2)
+        };
+
+        let bits1: Vec<bool> = BitIteratorBE::without_leading_zeros(k1.into_repr()).collect();
+        let bits2: Vec<bool> = BitIteratorBE::without_leading_zeros(k2.into_repr()).collect();
+        let len = bits1.len().max(bits2.len());
+
+        let bit_at = |bits: &[bool], i: usize| -> bool {
+            let pad = len - bits.len();
+            i >= pad && bits[i - pad]
+        };
+
+        let mut acc = GroupProjective::<Self>::zero();
+        for i in 0..len {
+            acc.double_in_place();
+            if bit_at(&bits1, i) {
+                acc.add_assign_mixed(&p1);
+            }
+            if bit_at(&bits2, i) {
+                acc.add_assign_mixed(&p2);
+            }
+        }
+        acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `wide_mul_u128` is what `scalar_decomposition` relies on to see past
+    /// `k`'s low 128 bits; a limb at index 2 or higher only reaches the
+    /// product if the whole `limbs` slice is multiplied, not just
+    /// `limbs[0]`/`limbs[1]`. `limbs = [0, 0, 1]` (`k = 2^128`) times `rhs =
+    /// 1` directly exercises that: the original truncating implementation
+    /// saw `k_u128 = 0` and produced an all-zero product here.
+    #[test]
+    fn wide_mul_u128_uses_limbs_beyond_the_low_128_bits() {
+        let limbs = [0u64, 0u64, 1u64];
+        let result = wide_mul_u128(&limbs, 1);
+        assert_eq!(result[2], 1);
+        assert!(result.iter().enumerate().all(|(i, &l)| i == 2 || l == 0));
+    }
+
+    /// A product that doesn't need the high limbs still has to come out
+    /// right, carries and all.
+    #[test]
+    fn wide_mul_u128_matches_u128_arithmetic_for_small_k() {
+        let limbs = [u64::MAX, 0u64];
+        let result = wide_mul_u128(&limbs, 3);
+        let expected = (u64::MAX as u128) * 3;
+        assert_eq!(result[0] as u128 | ((result[1] as u128) << 64), expected);
+        assert!(result[2..].iter().all(|&l| l == 0));
+    }
+
+    /// A multi-limb `rhs` (the high half of a 128-bit `G1`/`G2` constant)
+    /// must also be folded in, not dropped.
+    #[test]
+    fn wide_mul_u128_uses_the_high_64_bits_of_rhs() {
+        let limbs = [1u64];
+        let rhs = 1u128 << 100;
+        let result = wide_mul_u128(&limbs, rhs);
+        assert_eq!(result[0], 0);
+        assert_eq!(result[1], 1u64 << (100 - 64));
+        assert!(result[2..].iter().all(|&l| l == 0));
+    }
+}
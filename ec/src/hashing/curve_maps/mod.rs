@@ -0,0 +1,43 @@
+use crate::SWModelParameters;
+use ark_ff::{Field, Zero};
+
+pub mod swu;
+
+/// A rational map `(x, y) -> (x_num(x)/x_den(x), y * y_num(x)/y_den(x))`
+/// pushing a point on an isogenous curve through to a point on `P`.
+///
+/// Each of the four coefficient lists is stored in increasing degree order
+/// (constant term first), matching the layout used by the Sage isogeny
+/// tooling most curve parameter sets are generated from.
+#[derive(Clone, Copy, Debug)]
+pub struct IsogenyMap<'a, P: SWModelParameters> {
+    pub x_num: &'a [P::BaseField],
+    pub x_den: &'a [P::BaseField],
+    pub y_num: &'a [P::BaseField],
+    pub y_den: &'a [P::BaseField],
+}
+
+impl<'a, P: SWModelParameters> IsogenyMap<'a, P> {
+    /// Evaluate the isogeny at `(x, y)`, a point on the isogenous curve,
+    /// returning the corresponding point on `P`.
+    pub fn apply(&self, x: P::BaseField, y: P::BaseField) -> (P::BaseField, P::BaseField) {
+        let x_num = Self::horner(self.x_num, &x);
+        let x_den = Self::horner(self.x_den, &x);
+        let y_num = Self::horner(self.y_num, &x);
+        let y_den = Self::horner(self.y_den, &x);
+
+        let new_x = x_num * &x_den.inverse().unwrap();
+        let new_y = y * &(y_num * &y_den.inverse().unwrap());
+
+        (new_x, new_y)
+    }
+
+    /// Evaluate a polynomial given by its coefficients (lowest degree
+    /// first) at `x`, via Horner's method.
+    fn horner(coeffs: &[P::BaseField], x: &P::BaseField) -> P::BaseField {
+        coeffs
+            .iter()
+            .rev()
+            .fold(P::BaseField::zero(), |acc, c| acc * x + c)
+    }
+}
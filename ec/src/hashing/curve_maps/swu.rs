@@ -0,0 +1,240 @@
+use core::marker::PhantomData;
+
+use ark_ff::{Field, LegendreSymbol, One, PrimeField, SquareRootField, Zero};
+
+use crate::{
+    hashing::{curve_maps::IsogenyMap, HashToCurveError, MapToCurve},
+    models::short_weierstrass_jacobian::GroupAffine,
+    SWModelParameters,
+};
+
+/// Parameters for the simplified Shallue-van de Woestijne-Ulas (SWU) map,
+/// as specified in \[WB19, Section 4\].
+///
+/// The map is always applied to a curve `y^2 = x^3 + SWU_A x + SWU_B` with
+/// both coefficients nonzero. Curves whose own `COEFF_A`/`COEFF_B` satisfy
+/// that already set `SWU_A = COEFF_A`, `SWU_B = COEFF_B` and `ISOGENY_MAP =
+/// None`; curves with `COEFF_A = 0` (e.g. BLS12-377 G1) instead supply the
+/// parameters of a 3-isogenous curve together with the rational map that
+/// carries points back to `Self`.
+pub trait SWUParameters: SWModelParameters {
+    /// Coefficient `a` of the curve the SWU map is applied to.
+    const SWU_A: Self::BaseField;
+    /// Coefficient `b` of the curve the SWU map is applied to.
+    const SWU_B: Self::BaseField;
+    /// A non-square, non-(-1) field element satisfying the conditions of
+    /// \[WB19, Section 4\].
+    const Z: Self::BaseField;
+    /// The isogeny mapping the SWU curve back to `Self`, or `None` when the
+    /// SWU map is applied directly to `Self`.
+    const ISOGENY_MAP: Option<IsogenyMap<'static, Self>>;
+}
+
+/// [`MapToCurve`] implementation of the simplified SWU map, with an
+/// optional isogeny pushforward for curves whose `COEFF_A` is zero.
+pub struct SWUMap<P: SWUParameters>(PhantomData<fn() -> P>);
+
+impl<P: SWUParameters> MapToCurve<P> for SWUMap<P>
+where
+    P::BaseField: PrimeField + SquareRootField,
+{
+    fn new() -> Result<Self, HashToCurveError> {
+        if P::Z.is_zero() {
+            return Err(HashToCurveError::MapToCurveError(
+                "SWUParameters::Z must be nonzero".into(),
+            ));
+        }
+        if P::Z.legendre() == LegendreSymbol::QuadraticResidue {
+            return Err(HashToCurveError::MapToCurveError(
+                "SWUParameters::Z must be a non-square".into(),
+            ));
+        }
+        if P::Z == -P::BaseField::one() {
+            return Err(HashToCurveError::MapToCurveError(
+                "SWUParameters::Z must not be -1".into(),
+            ));
+        }
+        if P::SWU_A.is_zero() {
+            return Err(HashToCurveError::MapToCurveError(
+                "SWUParameters::SWU_A must be nonzero; the simplified SWU map does not apply \
+                 directly to a curve with a = 0, supply an isogenous curve's parameters instead"
+                    .into(),
+            ));
+        }
+        if P::SWU_B.is_zero() {
+            return Err(HashToCurveError::MapToCurveError(
+                "SWUParameters::SWU_B must be nonzero".into(),
+            ));
+        }
+        Ok(SWUMap(PhantomData))
+    }
+
+    fn map_to_curve(&self, u: P::BaseField) -> Result<GroupAffine<P>, HashToCurveError> {
+        let (a, b) = (P::SWU_A, P::SWU_B);
+
+        let g = |x: &P::BaseField| {
+            let mut t = x.square();
+            t += &a;
+            t *= x;
+            t += &b;
+            t
+        };
+
+        let u2 = u.square();
+        let z_u2 = P::Z * &u2;
+        let tv1 = (z_u2.square() + &z_u2).inverse();
+
+        let minus_b_over_a = {
+            let mut t = a.inverse().unwrap();
+            t *= &b;
+            -t
+        };
+
+        let x1 = match tv1 {
+            Some(tv1) => {
+                let mut t = tv1;
+                t += &P::BaseField::one();
+                t * &minus_b_over_a
+            },
+            // tv1 = 0 exactly when Z^2 u^4 + Z u^2 = 0: fall back to
+            // x1 = -b / (Z a), as specified in [WB19, Section 4].
+            None => {
+                let mut t = (P::Z * &a).inverse().unwrap();
+                t *= &b;
+                -t
+            },
+        };
+
+        let gx1 = g(&x1);
+        let (x, y) = if let Some(y1) = gx1.sqrt() {
+            (x1, y1)
+        } else {
+            let x2 = z_u2 * &x1;
+            let gx2 = g(&x2);
+            let y2 = gx2
+                .sqrt()
+                .ok_or_else(|| HashToCurveError::MapToCurveError("both g(x1) and g(x2) are non-square".into()))?;
+            (x2, y2)
+        };
+
+        // Fix the sign of y to match the sign of u.
+        let y = if sgn0(&y) == sgn0(&u) { y } else { -y };
+
+        let (x, y) = match P::ISOGENY_MAP {
+            Some(isogeny_map) => isogeny_map.apply(x, y),
+            None => (x, y),
+        };
+
+        Ok(GroupAffine::new(x, y, false))
+    }
+}
+
+/// The sign of a prime-field element, taken as the least-significant bit of
+/// its canonical representation (`sgn0_le` in RFC 9380's terminology).
+fn sgn0<F: PrimeField>(x: &F) -> bool {
+    x.into_repr().is_odd()
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::field_new;
+    use ark_test_curves::bls12_381::{Fq, Fr};
+
+    use super::*;
+    use crate::ModelParameters;
+
+    /// `Z = 11`, the non-square, non-`-1` constant RFC 9380 uses for the
+    /// BLS12-381 base field's SSWU suites; `SWU_A`/`SWU_B` are arbitrary
+    /// nonzero curve coefficients, since [`MapToCurve::map_to_curve`]'s
+    /// output satisfying `y^2 = x^3 + SWU_A x + SWU_B` doesn't depend on
+    /// them being *any particular* curve's parameters.
+    struct MockSWU;
+
+    impl ModelParameters for MockSWU {
+        type BaseField = Fq;
+        type ScalarField = Fr;
+    }
+
+    impl SWModelParameters for MockSWU {
+        const COEFF_A: Self::BaseField = field_new!(Fq, "1");
+        const COEFF_B: Self::BaseField = field_new!(Fq, "1");
+        const COFACTOR: &'static [u64] = &[1];
+        const COFACTOR_INV: Self::ScalarField = field_new!(Fr, "1");
+        const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+            (field_new!(Fq, "0"), field_new!(Fq, "1"));
+    }
+
+    impl SWUParameters for MockSWU {
+        const SWU_A: Self::BaseField = field_new!(Fq, "1");
+        const SWU_B: Self::BaseField = field_new!(Fq, "1");
+        const Z: Self::BaseField = field_new!(Fq, "11");
+        const ISOGENY_MAP: Option<IsogenyMap<'static, Self>> = None;
+    }
+
+    #[test]
+    fn new_rejects_a_square_z() {
+        struct SquareZ;
+        impl ModelParameters for SquareZ {
+            type BaseField = Fq;
+            type ScalarField = Fr;
+        }
+        impl SWModelParameters for SquareZ {
+            const COEFF_A: Self::BaseField = field_new!(Fq, "1");
+            const COEFF_B: Self::BaseField = field_new!(Fq, "1");
+            const COFACTOR: &'static [u64] = &[1];
+            const COFACTOR_INV: Self::ScalarField = field_new!(Fr, "1");
+            const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+                (field_new!(Fq, "0"), field_new!(Fq, "1"));
+        }
+        impl SWUParameters for SquareZ {
+            const SWU_A: Self::BaseField = field_new!(Fq, "1");
+            const SWU_B: Self::BaseField = field_new!(Fq, "1");
+            // 4 = 2^2 is a square for every prime field.
+            const Z: Self::BaseField = field_new!(Fq, "4");
+            const ISOGENY_MAP: Option<IsogenyMap<'static, Self>> = None;
+        }
+
+        assert!(<SWUMap<SquareZ> as MapToCurve<SquareZ>>::new().is_err());
+    }
+
+    #[test]
+    fn new_rejects_z_equal_to_minus_one() {
+        struct MinusOneZ;
+        impl ModelParameters for MinusOneZ {
+            type BaseField = Fq;
+            type ScalarField = Fr;
+        }
+        impl SWModelParameters for MinusOneZ {
+            const COEFF_A: Self::BaseField = field_new!(Fq, "1");
+            const COEFF_B: Self::BaseField = field_new!(Fq, "1");
+            const COFACTOR: &'static [u64] = &[1];
+            const COFACTOR_INV: Self::ScalarField = field_new!(Fr, "1");
+            const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+                (field_new!(Fq, "0"), field_new!(Fq, "1"));
+        }
+        impl SWUParameters for MinusOneZ {
+            const SWU_A: Self::BaseField = field_new!(Fq, "1");
+            const SWU_B: Self::BaseField = field_new!(Fq, "1");
+            const Z: Self::BaseField = field_new!(Fq, "-1");
+            const ISOGENY_MAP: Option<IsogenyMap<'static, Self>> = None;
+        }
+
+        assert!(<SWUMap<MinusOneZ> as MapToCurve<MinusOneZ>>::new().is_err());
+    }
+
+    #[test]
+    fn map_to_curve_produces_a_point_on_the_curve() {
+        let map = <SWUMap<MockSWU> as MapToCurve<MockSWU>>::new().unwrap();
+
+        for n in [0u64, 1, 2, 3, 1000] {
+            let u = Fq::from(n);
+            let point = map.map_to_curve(u).unwrap();
+
+            let mut rhs = point.x.square();
+            rhs += &MockSWU::SWU_A;
+            rhs *= &point.x;
+            rhs += &MockSWU::SWU_B;
+            assert_eq!(point.y.square(), rhs);
+        }
+    }
+}
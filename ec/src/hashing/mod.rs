@@ -0,0 +1,28 @@
+use crate::{models::short_weierstrass_jacobian::GroupAffine, SWModelParameters};
+use ark_std::string::String;
+
+pub mod curve_maps;
+
+/// Error type returned by a [`MapToCurve`] implementation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HashToCurveError {
+    /// The map could not place the given field element on the curve, or the
+    /// map's parameters failed the validity checks required by the
+    /// underlying construction (e.g. [WB19, Section 3]).
+    MapToCurveError(String),
+}
+
+/// A deterministic, well-defined map from a base field element to a point
+/// on an elliptic curve.
+///
+/// `MapToCurve` implementations are the low-level primitive used to build a
+/// full hash-to-curve construction (hash to base field, map to curve, clear
+/// cofactor); they need not be indifferentiable from a random oracle on
+/// their own.
+pub trait MapToCurve<P: SWModelParameters>: Sized {
+    /// Construct this map, validating any parameters it depends on.
+    fn new() -> Result<Self, HashToCurveError>;
+
+    /// Map a base field element to a point on the curve described by `P`.
+    fn map_to_curve(&self, point: P::BaseField) -> Result<GroupAffine<P>, HashToCurveError>;
+}
@@ -0,0 +1,10 @@
+//! Traits and structures for working with elliptic curve groups.
+
+pub mod batch_arith;
+pub mod glv;
+pub mod hashing;
+pub mod models;
+pub mod scalar_mul;
+pub mod wnaf;
+
+pub use models::*;
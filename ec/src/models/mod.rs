@@ -1,5 +1,6 @@
-use crate::models::short_weierstrass_jacobian::GroupAffine;
-use ark_ff::{fields::BitIteratorBE, Field, PrimeField, SquareRootField, Zero};
+use crate::models::short_weierstrass_jacobian::{GroupAffine, GroupProjective};
+use crate::models::twisted_edwards_extended::GroupAffine as TEGroupAffine;
+use ark_ff::{fields::BitIteratorBE, Field, One, PrimeField, SquareRootField, Zero};
 
 pub mod bls12;
 pub mod bn;
@@ -62,6 +63,42 @@ pub trait SWModelParameters: ModelParameters {
         item.mul_bits(BitIteratorBE::new(Self::ScalarField::characteristic()))
             .is_zero()
     }
+
+    /// Map an arbitrary point known to be on the curve into the prime-order
+    /// subgroup, by multiplying it by [`Self::COFACTOR`].
+    ///
+    /// Curves with an efficient endomorphism should override this with a
+    /// faster cofactor-clearing method; callers that only need a point in
+    /// the correct subgroup (e.g. hash-to-curve, or deserializing untrusted
+    /// points) should go through this method rather than open-coding a
+    /// cofactor multiplication.
+    fn clear_cofactor(item: &GroupAffine<Self>) -> GroupAffine<Self>
+    where
+        Self: Sized,
+    {
+        item.mul_bits(BitIteratorBE::without_leading_zeros(Self::COFACTOR))
+            .into()
+    }
+
+    /// Multiply an affine point on this curve by a scalar, returning a
+    /// projective result.
+    ///
+    /// The default implementation goes through [`crate::wnaf::mul_wnaf`],
+    /// so it's already faster than a plain double-and-add; curves with a
+    /// [`crate::glv::GLVParameters`] implementation should override this
+    /// with [`crate::glv::GLVParameters::mul_glv`] for another factor of
+    /// two. Generic code that scalar-multiplies through this method rather
+    /// than calling `mul_bits` directly benefits automatically from
+    /// whichever accelerated path is available.
+    fn mul_projective(
+        base: &GroupAffine<Self>,
+        scalar: impl Into<<Self::ScalarField as PrimeField>::BigInt>,
+    ) -> GroupProjective<Self>
+    where
+        Self: Sized,
+    {
+        crate::wnaf::mul_wnaf(*base, scalar.into(), None)
+    }
 }
 
 /// Model defined as twisted-edwards form
@@ -92,6 +129,35 @@ pub trait TEModelParameters: ModelParameters {
         copy *= &Self::COEFF_A;
         copy
     }
+
+    /// Map the `y`-coordinate of a twisted-edwards point to the
+    /// `u`-coordinate of the corresponding point on [`Self::MontgomeryModelParameters`],
+    /// via the standard birational equivalence `u = (1 + y) / (1 - y)`.
+    ///
+    /// Returns `None` at `y = 1`, the twisted-Edwards identity `(0, 1)`: it
+    /// maps to the point at infinity on the Montgomery curve, which has no
+    /// finite `u`-coordinate.
+    #[inline]
+    fn te_to_mont_u(y: Self::BaseField) -> Option<Self::BaseField> {
+        let mut num = Self::BaseField::one();
+        num += &y;
+        let mut den = Self::BaseField::one();
+        den -= &y;
+        den.inverse().map(|den_inv| num * &den_inv)
+    }
+
+    /// Map an arbitrary point known to be on the curve into the prime-order
+    /// subgroup, by multiplying it by [`Self::COFACTOR`].
+    ///
+    /// See [`SWModelParameters::clear_cofactor`] for the rationale; curves
+    /// with an efficient endomorphism should override this default.
+    fn clear_cofactor(item: &TEGroupAffine<Self>) -> TEGroupAffine<Self>
+    where
+        Self: Sized,
+    {
+        item.mul_bits(BitIteratorBE::without_leading_zeros(Self::COFACTOR))
+            .into()
+    }
 }
 
 /// Model defined as montgomery form
@@ -107,4 +173,171 @@ pub trait MontgomeryModelParameters: ModelParameters {
 
     /// Twisted-Edwards model with birational equivalence to this model
     type TEModelParameters: TEModelParameters<BaseField = Self::BaseField>;
+
+    /// Map a montgomery `u`-coordinate, together with its corresponding
+    /// `v`-coordinate, to the `(x, y)` coordinates of the birationally
+    /// equivalent point on [`Self::TEModelParameters`], via
+    /// `x = u / v`, `y = (u - 1) / (u + 1)`.
+    ///
+    /// Returns `None` at `v = 0` (the Montgomery curve's 2-torsion points,
+    /// which have no finite `x`) or `u = -1` (the point at infinity, which
+    /// has no finite `y`).
+    #[inline]
+    fn mont_u_to_te(
+        u: Self::BaseField,
+        v: Self::BaseField,
+    ) -> Option<(Self::BaseField, Self::BaseField)> {
+        let x = u * &v.inverse()?;
+
+        let mut y_num = u;
+        y_num -= &Self::BaseField::one();
+        let mut y_den = u;
+        y_den += &Self::BaseField::one();
+        let y = y_num * &y_den.inverse()?;
+
+        Some((x, y))
+    }
+
+    /// Multiply the `u`-coordinate of a point on this curve (or on its
+    /// quadratic twist) by a scalar, using the Montgomery ladder.
+    ///
+    /// The ladder operates on the projective pairs `(X2:Z2) = [m]P` and
+    /// `(X3:Z3) = [m+1]P`, whose difference is always the base point, and
+    /// processes `bits` from most significant to least significant. A zero
+    /// `Z` coordinate denotes the point at infinity.
+    ///
+    /// Because only the `u`-coordinate is used, and the curve equation and
+    /// its quadratic twist share the same `a`/`b` coefficients, this routine
+    /// works transparently for points on either curve: if `u` is the
+    /// `u`-coordinate of a twist point, the result is the `u`-coordinate of
+    /// the corresponding multiple of that twist point.
+    fn mul_u(u: Self::BaseField, bits: impl Iterator<Item = bool>) -> Self::BaseField {
+        // a24 = (COEFF_A + 2) / 4
+        let a24 = {
+            let mut t = Self::COEFF_A;
+            t += &Self::BaseField::from(2u8);
+            t * &Self::BaseField::from(4u8).inverse().unwrap()
+        };
+
+        // (X2:Z2) = [0]P, the point at infinity
+        let (mut x2, mut z2) = (Self::BaseField::one(), Self::BaseField::zero());
+        // (X3:Z3) = [1]P = P
+        let (mut x3, mut z3) = (u, Self::BaseField::one());
+
+        let mut swap = false;
+        for bit in bits {
+            swap ^= bit;
+            if swap {
+                core::mem::swap(&mut x2, &mut x3);
+                core::mem::swap(&mut z2, &mut z3);
+            }
+            swap = bit;
+
+            let a = x2 + &z2;
+            let aa = a.square();
+            let b = x2 - &z2;
+            let bb = b.square();
+            let e = aa - &bb;
+            let c = x3 + &z3;
+            let d = x3 - &z3;
+            let da = d * &a;
+            let cb = c * &b;
+
+            x3 = (da + &cb).square();
+            z3 = u * &(da - &cb).square();
+            x2 = aa * &bb;
+            z2 = e * &(bb + &(a24 * &e));
+        }
+        if swap {
+            core::mem::swap(&mut x2, &mut x3);
+            core::mem::swap(&mut z2, &mut z3);
+        }
+
+        x2 * &z2.inverse().unwrap_or_else(Self::BaseField::zero)
+    }
+
+    /// Multiply the `u`-coordinate of a point by a scalar field element.
+    #[inline]
+    fn mul(u: Self::BaseField, scalar: impl Into<<Self::ScalarField as PrimeField>::BigInt>) -> Self::BaseField {
+        Self::mul_u(u, BitIteratorBE::new(scalar.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::field_new;
+    use ark_test_curves::bls12_381::{Fq, Fr};
+
+    use super::*;
+
+    /// Birational-equivalence test parameters; `mul_u`'s identities below
+    /// hold for every `COEFF_A`, so the exact curve coefficients don't
+    /// matter, only that both sides of the TE/Montgomery correspondence are
+    /// wired up to each other.
+    struct MockTE;
+    struct MockMont;
+
+    impl ModelParameters for MockTE {
+        type BaseField = Fq;
+        type ScalarField = Fr;
+    }
+
+    impl TEModelParameters for MockTE {
+        const COEFF_A: Self::BaseField = field_new!(Fq, "1");
+        const COEFF_D: Self::BaseField = field_new!(Fq, "1");
+        const COFACTOR: &'static [u64] = &[1];
+        const COFACTOR_INV: Self::ScalarField = field_new!(Fr, "1");
+        const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+            (field_new!(Fq, "0"), field_new!(Fq, "1"));
+        type MontgomeryModelParameters = MockMont;
+    }
+
+    impl ModelParameters for MockMont {
+        type BaseField = Fq;
+        type ScalarField = Fr;
+    }
+
+    impl MontgomeryModelParameters for MockMont {
+        const COEFF_A: Self::BaseField = field_new!(Fq, "1");
+        const COEFF_B: Self::BaseField = field_new!(Fq, "1");
+        type TEModelParameters = MockTE;
+    }
+
+    #[test]
+    fn te_to_mont_u_rejects_the_te_identity() {
+        assert!(MockTE::te_to_mont_u(Fq::one()).is_none());
+    }
+
+    #[test]
+    fn te_to_mont_u_matches_the_birational_formula() {
+        let y = Fq::from(3u64);
+        let u = MockTE::te_to_mont_u(y).unwrap();
+        assert_eq!(u * &(Fq::one() - &y), Fq::one() + &y);
+    }
+
+    #[test]
+    fn mont_u_to_te_rejects_points_with_no_finite_image() {
+        assert!(MockMont::mont_u_to_te(Fq::from(2u64), Fq::zero()).is_none());
+        assert!(MockMont::mont_u_to_te(-Fq::one(), Fq::from(2u64)).is_none());
+    }
+
+    #[test]
+    fn mont_u_to_te_matches_the_birational_formula() {
+        let (u, v) = (Fq::from(3u64), Fq::from(5u64));
+        let (x, y) = MockMont::mont_u_to_te(u, v).unwrap();
+        assert_eq!(x * &v, u);
+        assert_eq!(y * &(u + &Fq::one()), u - &Fq::one());
+    }
+
+    #[test]
+    fn mul_u_of_the_zero_scalar_is_the_point_at_infinity() {
+        let u = Fq::from(7u64);
+        assert!(MockMont::mul_u(u, core::iter::empty()).is_zero());
+    }
+
+    #[test]
+    fn mul_u_of_one_returns_the_same_u_coordinate() {
+        let u = Fq::from(7u64);
+        assert_eq!(MockMont::mul_u(u, core::iter::once(true)), u);
+    }
 }
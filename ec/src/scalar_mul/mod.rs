@@ -0,0 +1,3 @@
+pub mod variable_base;
+
+pub use variable_base::VariableBaseMSM;
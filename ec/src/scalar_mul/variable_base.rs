@@ -0,0 +1,172 @@
+use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_std::vec::Vec;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{
+    models::short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    SWModelParameters,
+};
+
+/// Variable-base multi-scalar multiplication: computes `Σ scalars[i] ·
+/// bases[i]` via Pippenger's bucket method, which beats a naive
+/// per-term scalar multiplication by roughly `log(n)` once there are more
+/// than a handful of terms.
+pub struct VariableBaseMSM;
+
+impl VariableBaseMSM {
+    /// Compute `Σ scalars[i] · bases[i]`.
+    ///
+    /// Returns `Err(len)` with the length of the shorter slice if `bases`
+    /// and `scalars` don't have the same length.
+    pub fn msm<P: SWModelParameters>(
+        bases: &[GroupAffine<P>],
+        scalars: &[<P::ScalarField as PrimeField>::BigInt],
+    ) -> Result<GroupProjective<P>, usize> {
+        if bases.len() != scalars.len() {
+            return Err(bases.len().min(scalars.len()));
+        }
+        Ok(Self::multi_scalar_mul(bases, scalars))
+    }
+
+    /// The Pippenger bucket method itself, assuming `bases` and `scalars`
+    /// already have matching length.
+    ///
+    /// Scalars are split into `⌈bits/c⌉` windows of `c` bits each. Within a
+    /// window, every base is accumulated into one of `2^c - 1` buckets
+    /// keyed by that window's digit; each window is then reduced to a
+    /// single sum with the standard running-sum trick (scanning buckets
+    /// from the top down, accumulating a running sum and adding it into the
+    /// total at every step), and the per-window sums are combined from most
+    /// to least significant with `c` doublings in between.
+    fn multi_scalar_mul<P: SWModelParameters>(
+        bases: &[GroupAffine<P>],
+        scalars: &[<P::ScalarField as PrimeField>::BigInt],
+    ) -> GroupProjective<P> {
+        let size = bases.len().min(scalars.len());
+        let scalars = &scalars[..size];
+        let bases = &bases[..size];
+
+        let c = if size < 32 {
+            3
+        } else {
+            ln_without_floats(size) + 2
+        };
+
+        let num_bits = P::ScalarField::size_in_bits();
+        let fr_one = P::ScalarField::one().into_repr();
+
+        let zero = GroupProjective::<P>::zero();
+        let window_starts: Vec<usize> = (0..num_bits).step_by(c).collect();
+
+        #[cfg(feature = "parallel")]
+        let window_starts_iter = window_starts.into_par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let window_starts_iter = window_starts.into_iter();
+
+        // Each window is independent, so windows can be computed in
+        // parallel and only combined at the end.
+        let window_sums: Vec<_> = window_starts_iter
+            .map(|w_start| {
+                let mut res = zero;
+                let mut buckets = ark_std::vec![zero; (1 << c) - 1];
+
+                scalars
+                    .iter()
+                    .zip(bases)
+                    .filter(|(s, _)| !s.is_zero())
+                    .for_each(|(&scalar, base)| {
+                        if scalar == fr_one {
+                            // This base contributes `1 · base`: only add it
+                            // in at the lowest window, to avoid double
+                            // counting.
+                            if w_start == 0 {
+                                res.add_assign_mixed(base);
+                            }
+                        } else {
+                            let mut scalar = scalar;
+                            scalar.divn(w_start as u32);
+                            let digit = (scalar.as_ref()[0] % (1 << c)) as usize;
+                            if digit != 0 {
+                                buckets[digit - 1].add_assign_mixed(base);
+                            }
+                        }
+                    });
+
+                let mut running_sum = zero;
+                for bucket in buckets.into_iter().rev() {
+                    running_sum += &bucket;
+                    res += &running_sum;
+                }
+                res
+            })
+            .collect();
+
+        // Combine the window sums, most significant window first.
+        let lowest = *window_sums.first().unwrap();
+        lowest
+            + &window_sums[1..]
+                .iter()
+                .rev()
+                .fold(zero, |mut total, sum_i| {
+                    total += sum_i;
+                    for _ in 0..c {
+                        total.double_in_place();
+                    }
+                    total
+                })
+    }
+}
+
+/// A cheap integer approximation of `log2(a) * ln(2)`, used to pick a
+/// sensible Pippenger window width without pulling in floating point.
+fn ln_without_floats(a: usize) -> usize {
+    (ark_std::log2(a) * 69 / 100) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::field_new;
+    use ark_test_curves::bls12_381::{Fq, Fr};
+
+    use super::*;
+    use crate::ModelParameters;
+
+    /// Minimal curve parameters for exercising [`VariableBaseMSM::msm`]'s
+    /// length-checking and empty-input paths, which never touch an actual
+    /// curve point; this crate's `short_weierstrass_jacobian` module (which
+    /// would be needed to build and add real points) isn't implemented here.
+    struct MockParams;
+
+    impl ModelParameters for MockParams {
+        type BaseField = Fq;
+        type ScalarField = Fr;
+    }
+
+    impl SWModelParameters for MockParams {
+        const COEFF_A: Self::BaseField = field_new!(Fq, "0");
+        const COEFF_B: Self::BaseField = field_new!(Fq, "1");
+        const COFACTOR: &'static [u64] = &[1];
+        const COFACTOR_INV: Self::ScalarField = field_new!(Fr, "1");
+        const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+            (field_new!(Fq, "0"), field_new!(Fq, "1"));
+    }
+
+    #[test]
+    fn msm_reports_the_shorter_length_on_mismatch() {
+        let scalars = [Fr::from(1u64).into_repr(), Fr::from(2u64).into_repr()];
+        let bases: [GroupAffine<MockParams>; 0] = [];
+        assert_eq!(VariableBaseMSM::msm(&bases, &scalars), Err(0));
+    }
+
+    #[test]
+    fn msm_of_no_terms_is_the_identity() {
+        let bases: [GroupAffine<MockParams>; 0] = [];
+        let scalars: [<Fr as PrimeField>::BigInt; 0] = [];
+        assert_eq!(
+            VariableBaseMSM::msm(&bases, &scalars),
+            Ok(GroupProjective::<MockParams>::zero())
+        );
+    }
+}